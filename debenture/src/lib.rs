@@ -9,6 +9,7 @@ mod tests;
 use core::ops::Div;
 
 use chrono::format::Fixed;
+use chrono::Datelike;
 use soroban_sdk::{
     contractimpl, BigInt, Binary, Env, EnvType, FixedBinary, IntoVal, RawVal, Symbol, Vec,
 };
@@ -21,8 +22,20 @@ pub enum DataKey {
     ParValue = 2,
     DebentureHolder = 3,
     CouponPaymentFrequency = 4,
+    IssueDate = 5,
+    DayCount = 6,
+    MaxExtension = 7,
+    ExtendedSeconds = 8,
+    AccumulatedRate = 9,
+    LastAccrued = 10,
+    Metadata = 11,
+    Issuer = 12,
 }
 
+// Fixed-point scale used by the compound-interest accumulator: `AccumulatedRate`
+// is stored as an integer multiple of this, with `ACCRUAL_SCALE` representing 1.0.
+const ACCRUAL_SCALE: u64 = 1_000_000_000;
+
 impl IntoVal<Env, RawVal> for DataKey {
     fn into_val(self, env: &Env) -> RawVal {
         (self as u32).into_val(env)
@@ -74,6 +87,76 @@ impl IntoVal<Env, RawVal> for CouponPaymentFrequency {
     }
 }
 
+/// The day-count convention used to compute accrued interest between coupon dates.
+#[derive(Clone, Copy)]
+#[repr(u32)]
+pub enum DayCount {
+    /// Real elapsed days over a 360-day year.
+    Actual360 = 0,
+    /// Real elapsed days over a 365-day year.
+    Actual365 = 1,
+    /// The 30/360 convention, with the standard 31-day clamping rule.
+    Thirty360 = 2,
+    /// Real elapsed days over the real number of days in the coupon period.
+    ActualActual = 3,
+}
+
+impl From<u32> for DayCount {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => DayCount::Actual360,
+            1 => DayCount::Actual365,
+            2 => DayCount::Thirty360,
+            3 => DayCount::ActualActual,
+            _ => panic!("Invalid value for DayCount: {}", value),
+        }
+    }
+}
+
+impl IntoVal<Env, RawVal> for DayCount {
+    fn into_val(self, env: &Env) -> RawVal {
+        (self as u32).into_val(env)
+    }
+}
+
+/// Regulatory and descriptive identifiers for a debenture, modeled on the
+/// fields a bond front-end or explorer needs beyond the numeric coupon terms.
+#[derive(Clone)]
+pub struct Metadata {
+    /// The instrument's ISIN.
+    pub isin: Binary,
+    /// The legal name of the issuer.
+    pub issuer_name: Binary,
+    /// The currency the debenture is denominated in.
+    pub currency: Binary,
+    /// The face value denomination.
+    pub denomination: u32,
+}
+
+type MetadataTuple = (Binary, Binary, Binary, u32);
+
+impl From<Metadata> for MetadataTuple {
+    fn from(metadata: Metadata) -> Self {
+        (
+            metadata.isin,
+            metadata.issuer_name,
+            metadata.currency,
+            metadata.denomination,
+        )
+    }
+}
+
+impl From<MetadataTuple> for Metadata {
+    fn from(tuple: MetadataTuple) -> Self {
+        Metadata {
+            isin: tuple.0,
+            issuer_name: tuple.1,
+            currency: tuple.2,
+            denomination: tuple.3,
+        }
+    }
+}
+
 /**
  * Debenture Interface
  *
@@ -84,13 +167,19 @@ impl IntoVal<Env, RawVal> for CouponPaymentFrequency {
  */
 pub trait Debenture {
     /// Issue a new debenture, this is the initialization of the debenture contract.
-    /// Coupon rate is specified as basis points per annum.
+    /// Coupon rate is specified as basis points per annum. `day_count` selects the
+    /// convention used by `accrued_interest` to prorate a partial coupon period.
+    /// `max_extension` caps the cumulative amount of time `extend_maturity` may
+    /// roll the maturity forward over the life of the debenture.
     fn issue(
         e: Env,
+        issue_date: BigInt,
         maturity: BigInt,
         coupon_rate: BigInt,
         par_value: BigInt,
         coupon_payment_frequency: u32,
+        day_count: u32,
+        max_extension: BigInt,
         debenture_holder: FixedBinary<32>,
     );
 
@@ -111,6 +200,15 @@ pub trait Debenture {
 
     /// Return the coupon payment for the current period.
     fn coupon_payment(e: Env, timestamp: BigInt) -> BigInt;
+
+    /// Return the interest accrued since the last coupon date up to `timestamp`,
+    /// prorated within the current coupon period using the debenture's day-count
+    /// convention. This is what a holder selling mid-period is owed.
+    fn accrued_interest(e: Env, timestamp: BigInt) -> BigInt;
+
+    /// Return the full series of `(payment_timestamp, amount)` cashflows from
+    /// issue to maturity, including the final principal redemption.
+    fn cashflows(e: Env) -> Vec<(BigInt, BigInt)>;
 }
 
 fn get_maturity(e: &Env) -> BigInt {
@@ -148,6 +246,152 @@ fn get_debenture_holder(e: &Env) -> FixedBinary<32> {
         .unwrap()
 }
 
+fn get_issue_date(e: &Env) -> BigInt {
+    e.contract_data()
+        .get(DataKey::IssueDate)
+        .unwrap_or(Ok(BigInt::zero(e)))
+        .unwrap()
+}
+
+fn get_day_count(e: &Env) -> u32 {
+    e.contract_data()
+        .get(DataKey::DayCount)
+        .unwrap_or(Ok(0))
+        .unwrap()
+}
+
+fn get_max_extension(e: &Env) -> BigInt {
+    e.contract_data()
+        .get(DataKey::MaxExtension)
+        .unwrap_or(Ok(BigInt::zero(e)))
+        .unwrap()
+}
+
+fn get_extended_seconds(e: &Env) -> BigInt {
+    e.contract_data()
+        .get(DataKey::ExtendedSeconds)
+        .unwrap_or(Ok(BigInt::zero(e)))
+        .unwrap()
+}
+
+fn get_accumulated_rate(e: &Env) -> BigInt {
+    e.contract_data()
+        .get(DataKey::AccumulatedRate)
+        .unwrap_or(Ok(BigInt::from_u64(e, ACCRUAL_SCALE)))
+        .unwrap()
+}
+
+fn get_last_accrued(e: &Env) -> BigInt {
+    e.contract_data()
+        .get(DataKey::LastAccrued)
+        .unwrap_or(Ok(BigInt::zero(e)))
+        .unwrap()
+}
+
+fn get_issuer(e: &Env) -> FixedBinary<32> {
+    e.contract_data()
+        .get(DataKey::Issuer)
+        .unwrap_or(Ok(FixedBinary::from_array(e, [0u8; 32])))
+        .unwrap()
+}
+
+fn get_metadata(e: &Env) -> Metadata {
+    let tuple: MetadataTuple = e
+        .contract_data()
+        .get(DataKey::Metadata)
+        .unwrap_or(Ok((
+            Binary::new(e),
+            Binary::new(e),
+            Binary::new(e),
+            0,
+        )))
+        .unwrap();
+    tuple.into()
+}
+
+// Raise `base` (scaled by `scale`) to `exponent` using exponentiation by
+// squaring, since BigInt here has no `checked_pow`.
+fn pow_scaled(e: &Env, mut base: BigInt, mut exponent: BigInt, scale: &BigInt) -> BigInt {
+    let zero = BigInt::zero(e);
+    let two = BigInt::from_u32(e, 2);
+    let mut result = scale.clone();
+
+    while exponent > zero {
+        let half = exponent.clone() / two.clone();
+        let is_odd = exponent.clone() - (half.clone() * two.clone()) != zero;
+
+        if is_odd {
+            result = (result * base.clone()) / scale.clone();
+        }
+        base = (base.clone() * base.clone()) / scale.clone();
+        exponent = half;
+    }
+
+    result
+}
+
+// Decompose a unix timestamp (in seconds) into a (year, month, day) triple.
+fn seconds_to_ymd(timestamp: &BigInt) -> (i32, u32, u32) {
+    let secs = timestamp.to_i64().unwrap();
+    let date = chrono::NaiveDateTime::from_timestamp_opt(secs, 0).unwrap();
+    (date.year(), date.month(), date.day())
+}
+
+// Compute the accrual fraction for `timestamp` within [period_start, period_end),
+// per the selected day-count convention, as a (numerator, denominator) pair.
+// `payment_frequency` is needed because `periodic_coupon` (what this fraction
+// prorates) is already the annual coupon divided by the payment frequency, so
+// for Actual360/Actual365 the elapsed days are multiplied by the frequency to
+// measure them against a single coupon period rather than a full year.
+fn day_count_fraction(
+    e: &Env,
+    day_count: &DayCount,
+    period_start: &BigInt,
+    period_end: &BigInt,
+    timestamp: &BigInt,
+    payment_frequency: &BigInt,
+) -> (BigInt, BigInt) {
+    let seconds_per_day = BigInt::from_u32(e, 86400);
+    match day_count {
+        DayCount::Actual360 => {
+            let elapsed = (timestamp.clone() - period_start.clone()) / seconds_per_day;
+            // Scale the numerator by payment_frequency instead of dividing it
+            // into the 360 denominator, which would truncate to zero for any
+            // frequency above 360 (e.g. Daily) and panic on division later.
+            (elapsed * payment_frequency.clone(), BigInt::from_u32(e, 360))
+        }
+        DayCount::Actual365 => {
+            let elapsed = (timestamp.clone() - period_start.clone()) / seconds_per_day;
+            (elapsed * payment_frequency.clone(), BigInt::from_u32(e, 365))
+        }
+        DayCount::ActualActual => {
+            let elapsed = (timestamp.clone() - period_start.clone()) / seconds_per_day.clone();
+            let total = (period_end.clone() - period_start.clone()) / seconds_per_day;
+            (elapsed, total)
+        }
+        DayCount::Thirty360 => {
+            let (y1, m1, d1) = seconds_to_ymd(period_start);
+            let (y2, m2, d2) = seconds_to_ymd(timestamp);
+            let elapsed = BigInt::from_i64(e, thirty_360_days(y1, m1, d1, y2, m2, d2));
+
+            let (py1, pm1, pd1) = seconds_to_ymd(period_start);
+            let (py2, pm2, pd2) = seconds_to_ymd(period_end);
+            let total = BigInt::from_i64(e, thirty_360_days(py1, pm1, pd1, py2, pm2, pd2));
+
+            (elapsed, total)
+        }
+    }
+}
+
+// The 30/360 day-count, with the standard clamping rule: a 31st is treated as
+// the 30th, and the end date is also clamped to the 30th if the start date was.
+fn thirty_360_days(y1: i32, m1: u32, d1: u32, y2: i32, m2: u32, d2: u32) -> i64 {
+    let d1 = if d1 == 31 { 30 } else { d1 };
+    let d2 = if d2 == 31 && d1 == 30 { 30 } else { d2 };
+
+    (360 * (y2 - y1) as i64) + (30 * (m2 as i64 - m1 as i64)) + (d2 as i64 - d1 as i64)
+}
+
 // Calculate the coupon payment for the debenture
 fn get_coupon_payment(e: &Env, timestamp: BigInt) -> BigInt {
     let maturity = get_maturity(e);
@@ -163,18 +407,99 @@ fn get_coupon_payment(e: &Env, timestamp: BigInt) -> BigInt {
     (par_value * (coupon_rate / payment_frequency)) / BigInt::from_u32(e, 100)
 }
 
+// Calculate the interest accrued within the current coupon period, up to `timestamp`.
+fn get_accrued_interest(e: &Env, timestamp: BigInt) -> BigInt {
+    let maturity = get_maturity(e);
+    if timestamp > maturity {
+        return BigInt::zero(e);
+    }
+
+    let issue_date = get_issue_date(e);
+    let payment_frequency = CouponPaymentFrequency::from(get_coupon_frequency(e)).into_big_int(e);
+    let seconds_per_year = BigInt::from_u32(e, 365 * 24 * 60 * 60);
+    let period_length = seconds_per_year / payment_frequency.clone();
+
+    let periods_elapsed = (timestamp.clone() - issue_date.clone()) / period_length.clone();
+    let prev_coupon_date = issue_date + period_length.clone() * periods_elapsed;
+    let next_coupon_date = prev_coupon_date.clone() + period_length;
+
+    let periodic_coupon = get_coupon_payment(e, timestamp.clone());
+    let day_count = DayCount::from(get_day_count(e));
+    let (days_elapsed, days_in_period) = day_count_fraction(
+        e,
+        &day_count,
+        &prev_coupon_date,
+        &next_coupon_date,
+        &timestamp,
+        &payment_frequency,
+    );
+
+    (periodic_coupon * days_elapsed) / days_in_period
+}
+
+// A cashflow schedule holds at most this many coupon dates before maturity,
+// plus the final redemption; schedules that would exceed it (e.g. a daily
+// coupon over a decade-long maturity) are rejected with a panic.
+const MAX_CASHFLOWS: u32 = 1024;
+
+// Build the full coupon cashflow schedule, stepping forward from the issue date
+// in increments of one coupon period, plus the final principal redemption.
+// The coupon terms are invariant across periods (the periodic coupon doesn't
+// depend on the timestamp, only on whether it's past maturity), so they're
+// loaded once rather than re-read from storage on every iteration.
+fn get_cashflows(e: &Env) -> Vec<(BigInt, BigInt)> {
+    let issue_date = get_issue_date(e);
+    let maturity = get_maturity(e);
+    let par_value = get_par_value(e);
+    let coupon_rate = get_coupon_rate(e);
+    let payment_frequency = CouponPaymentFrequency::from(get_coupon_frequency(e)).into_big_int(e);
+    let seconds_per_year = BigInt::from_u32(e, 365 * 24 * 60 * 60);
+    let period_length = seconds_per_year / payment_frequency.clone();
+    let periodic_coupon =
+        (par_value.clone() * (coupon_rate / payment_frequency)) / BigInt::from_u32(e, 100);
+
+    let mut cashflows = Vec::new(e);
+    let mut payment_date = issue_date + period_length.clone();
+    let mut periods: u32 = 0;
+
+    while payment_date < maturity {
+        periods += 1;
+        if periods > MAX_CASHFLOWS {
+            panic!("cashflow schedule exceeds the maximum number of coupon periods supported per call");
+        }
+
+        cashflows.push_back((payment_date.clone(), periodic_coupon.clone()));
+        payment_date = payment_date + period_length.clone();
+    }
+
+    cashflows.push_back((maturity, par_value + periodic_coupon));
+
+    cashflows
+}
+
 pub struct DebentureContract;
 
 #[contractimpl(export_if = "export")]
 impl Debenture for DebentureContract {
     fn issue(
         e: Env,
+        issue_date: BigInt,
         maturity: BigInt,
         coupon_rate: BigInt,
         par_value: BigInt,
         coupon_payment_frequency: u32,
+        day_count: u32,
+        max_extension: BigInt,
         debenture_holder: FixedBinary<32>,
     ) {
+        // Record the caller as the issuer, the only party authorized to
+        // later extend the maturity or edit the instrument's metadata.
+        e.contract_data().set(DataKey::Issuer, get_invoker(&e));
+
+        // Set the issue date of the debenture, the anchor for its coupon schedule.
+        e.contract_data()
+            .set(DataKey::IssueDate, issue_date.clone());
+
         // Set the maturity of the debenture.
         e.contract_data().set(DataKey::Maturity, maturity);
 
@@ -191,6 +516,21 @@ impl Debenture for DebentureContract {
         // Set the coupon payment frequency of the debenture.
         e.contract_data()
             .set(DataKey::CouponPaymentFrequency, coupon_payment_frequency);
+
+        // Set the day-count convention used to prorate partial coupon periods.
+        e.contract_data().set(DataKey::DayCount, day_count);
+
+        // Set the cap on cumulative maturity extension.
+        e.contract_data().set(DataKey::MaxExtension, max_extension);
+
+        // No maturity extension has been granted yet.
+        e.contract_data()
+            .set(DataKey::ExtendedSeconds, BigInt::zero(&e));
+
+        // The compound-interest accumulator starts at 1.0 (scaled) at issue.
+        e.contract_data()
+            .set(DataKey::AccumulatedRate, BigInt::from_u64(&e, ACCRUAL_SCALE));
+        e.contract_data().set(DataKey::LastAccrued, issue_date);
     }
 
     fn maturity(e: Env) -> BigInt {
@@ -219,4 +559,177 @@ impl Debenture for DebentureContract {
     fn coupon_payment(e: Env, timestamp: BigInt) -> BigInt {
         get_coupon_payment(&e, timestamp)
     }
+
+    fn accrued_interest(e: Env, timestamp: BigInt) -> BigInt {
+        get_accrued_interest(&e, timestamp)
+    }
+
+    fn cashflows(e: Env) -> Vec<(BigInt, BigInt)> {
+        get_cashflows(&e)
+    }
+}
+
+#[contractimpl(export_if = "export")]
+impl DebentureContract {
+    /// Roll the maturity forward by `additional_seconds`, so long as the
+    /// cumulative extension granted since issue stays within `max_extension`.
+    pub fn extend_maturity(e: Env, additional_seconds: BigInt) {
+        require_issuer_authorization(&e);
+
+        if additional_seconds < BigInt::zero(&e) {
+            panic!("additional_seconds must not be negative");
+        }
+
+        let extended_seconds = get_extended_seconds(&e) + additional_seconds.clone();
+        if extended_seconds > get_max_extension(&e) {
+            panic!("Maturity extension exceeds the maximum allowed for this debenture");
+        }
+
+        let maturity = get_maturity(&e) + additional_seconds;
+        e.contract_data().set(DataKey::Maturity, maturity);
+        e.contract_data()
+            .set(DataKey::ExtendedSeconds, extended_seconds);
+    }
+
+    /// Transfer the debenture to a new holder, authorized by the current holder.
+    pub fn transfer(e: Env, from: FixedBinary<32>, to: FixedBinary<32>) {
+        let holder = get_debenture_holder(&e);
+        if from != holder {
+            panic!("Only the current debenture holder may transfer it");
+        }
+
+        require_holder_authorization(&e, &from);
+
+        e.contract_data().set(DataKey::DebentureHolder, to.clone());
+
+        e.events()
+            .publish((Symbol::from_str("transfer"),), (from, to));
+    }
+
+    /// Advance the compound-interest accumulator to `now`, compounding the
+    /// coupon rate once per elapsed coupon period since the last accrual.
+    pub fn accrue(e: Env, now: BigInt) {
+        let last_accrued = get_last_accrued(&e);
+        if now < last_accrued {
+            panic!("accrual time must not precede the last accrual");
+        }
+
+        let payment_frequency =
+            CouponPaymentFrequency::from(get_coupon_frequency(&e)).into_big_int(&e);
+        let seconds_per_year = BigInt::from_u32(&e, 365 * 24 * 60 * 60);
+        let period_length = seconds_per_year / payment_frequency.clone();
+
+        let periods_elapsed = (now - last_accrued.clone()) / period_length.clone();
+        if periods_elapsed == BigInt::zero(&e) {
+            return;
+        }
+
+        let scale = BigInt::from_u64(&e, ACCRUAL_SCALE);
+        let coupon_rate = get_coupon_rate(&e);
+        // The coupon rate is per annum, so the per-period rate must be
+        // divided by the payment frequency, matching `get_coupon_payment`.
+        let rate_per_period = scale.clone()
+            + (scale.clone() * coupon_rate) / (BigInt::from_u32(&e, 10000) * payment_frequency);
+
+        let accumulated_rate = get_accumulated_rate(&e);
+        let growth = pow_scaled(&e, rate_per_period, periods_elapsed.clone(), &scale);
+        let updated_rate = (accumulated_rate * growth) / scale;
+
+        // Only advance by the periods actually consumed, so any sub-period
+        // remainder in `now` isn't dropped from future accrual windows.
+        let accrued_through = last_accrued + periods_elapsed * period_length;
+
+        e.contract_data()
+            .set(DataKey::AccumulatedRate, updated_rate);
+        e.contract_data()
+            .set(DataKey::LastAccrued, accrued_through);
+    }
+
+    /// Return the compounded interest accrued since issue, computed from the
+    /// cached accumulator: `par_value * (acc - acc_at_issue)`.
+    pub fn compounded_interest(e: Env) -> BigInt {
+        let scale = BigInt::from_u64(&e, ACCRUAL_SCALE);
+        let accumulated_rate = get_accumulated_rate(&e);
+        let par_value = get_par_value(&e);
+
+        (par_value * (accumulated_rate - scale.clone())) / scale
+    }
+
+    /// Set the instrument's regulatory and descriptive metadata.
+    pub fn set_metadata(
+        e: Env,
+        isin: Binary,
+        issuer_name: Binary,
+        currency: Binary,
+        denomination: u32,
+    ) {
+        require_issuer_authorization(&e);
+
+        let metadata = Metadata {
+            isin,
+            issuer_name,
+            currency,
+            denomination,
+        };
+        e.contract_data()
+            .set(DataKey::Metadata, MetadataTuple::from(metadata));
+    }
+
+    /// Return the instrument's regulatory and descriptive metadata.
+    pub fn metadata(e: Env) -> Metadata {
+        get_metadata(&e)
+    }
+
+    /// Issue a new debenture together with its metadata, so a freshly issued
+    /// instrument carries its regulatory identifiers on-chain from the start.
+    pub fn issue_with_metadata(
+        e: Env,
+        issue_date: BigInt,
+        maturity: BigInt,
+        coupon_rate: BigInt,
+        par_value: BigInt,
+        coupon_payment_frequency: u32,
+        day_count: u32,
+        max_extension: BigInt,
+        debenture_holder: FixedBinary<32>,
+        isin: Binary,
+        issuer_name: Binary,
+        currency: Binary,
+        denomination: u32,
+    ) {
+        DebentureContract::issue(
+            e.clone(),
+            issue_date,
+            maturity,
+            coupon_rate,
+            par_value,
+            coupon_payment_frequency,
+            day_count,
+            max_extension,
+            debenture_holder,
+        );
+        DebentureContract::set_metadata(e, isin, issuer_name, currency, denomination);
+    }
+}
+
+// Identify the account or contract that invoked the current call.
+fn get_invoker(e: &Env) -> FixedBinary<32> {
+    match e.invoker() {
+        EnvType::Contract(id) => id,
+        EnvType::Account(id) => id,
+    }
+}
+
+// Verify that the current invocation was authorized by `holder`.
+fn require_holder_authorization(e: &Env, holder: &FixedBinary<32>) {
+    if &get_invoker(e) != holder {
+        panic!("transfer must be authorized by the current debenture holder");
+    }
+}
+
+// Verify that the current invocation was authorized by the issuer.
+fn require_issuer_authorization(e: &Env) {
+    if get_invoker(e) != get_issuer(e) {
+        panic!("this action must be authorized by the issuer");
+    }
 }
@@ -11,12 +11,40 @@ fn generate_contract_id() -> [u8; 32] {
     id
 }
 
+// A minimal pass-through contract with no purpose beyond testing: calling a
+// gated `DebentureContract` entry point through this contract makes
+// `Env::invoker()` resolve to `Caller`'s own contract id inside that entry
+// point, giving the authorization tests a genuinely different invoker to
+// reject (a direct, same-process `xxx::invoke` call can never do that, since
+// it always runs under the harness's own default invoker).
+pub struct Caller;
+
+#[contractimpl(export_if = "export")]
+impl Caller {
+    pub fn xfer(e: Env, target: FixedBinary<32>, from: FixedBinary<32>, to: FixedBinary<32>) {
+        e.invoke_contract::<()>(
+            &target,
+            &Symbol::from_str("transfer"),
+            vec![&e, from.into_val(&e), to.into_val(&e)],
+        );
+    }
+
+    pub fn extend(e: Env, target: FixedBinary<32>, additional_seconds: BigInt) {
+        e.invoke_contract::<()>(
+            &target,
+            &Symbol::from_str("extend_maturity"),
+            vec![&e, additional_seconds.into_val(&e)],
+        );
+    }
+}
+
 #[test]
 fn test() {
     let env = Env::default();
     let contract_id = FixedBinary::from_array(&env, generate_contract_id());
     env.register_contract(&contract_id, DebentureContract);
 
+    let issue_date = BigInt::from_i64(&env, chrono::Utc::now().timestamp());
     let maturity = BigInt::from_i64(
         &env,
         chrono::Utc::now()
@@ -30,10 +58,13 @@ fn test() {
     issue::invoke(
         &env,
         &contract_id,
+        &issue_date,
         &maturity,
         &coupon_rate,
         &par_value,
         &(CouponPaymentFrequency::Annually as u32),
+        &(DayCount::Actual365 as u32),
+        &BigInt::from_i64(&env, 30 * 24 * 60 * 60),
         &debenture_holder,
     );
 
@@ -42,4 +73,595 @@ fn test() {
 
     // assert the maturity is correct
     assert_eq!(maturity, retrieved_maturity, "maturity is incorrect");
+
+    // no time has passed since issuance, so nothing has accrued yet
+    let accrued = accrued_interest::invoke(&env, &contract_id, &issue_date);
+    assert_eq!(
+        accrued,
+        BigInt::zero(&env),
+        "accrued interest at issuance should be zero"
+    );
+}
+
+#[test]
+fn test_accrued_interest_actual365_quarterly_matches_hand_computed_value() {
+    let env = Env::default();
+    let contract_id = FixedBinary::from_array(&env, generate_contract_id());
+    env.register_contract(&contract_id, DebentureContract);
+
+    let issue_date = BigInt::from_i64(&env, chrono::Utc::now().timestamp());
+    let maturity = issue_date
+        .clone()
+        .add(BigInt::from_i64(&env, 3650 * 24 * 60 * 60));
+    let coupon_rate = BigInt::from_u32(&env, 800);
+    let par_value = BigInt::from_u64(&env, 1e5 as u64);
+    let debenture_holder = FixedBinary::from_array(&env, generate_contract_id());
+    let max_extension = BigInt::from_i64(&env, 30 * 24 * 60 * 60);
+
+    issue::invoke(
+        &env,
+        &contract_id,
+        &issue_date,
+        &maturity,
+        &coupon_rate,
+        &par_value,
+        &(CouponPaymentFrequency::Quarterly as u32),
+        &(DayCount::Actual365 as u32),
+        &max_extension,
+        &debenture_holder,
+    );
+
+    // Halfway (in seconds) through the first quarterly period.
+    let timestamp = issue_date.add(BigInt::from_i64(&env, 3_942_000));
+    let accrued = accrued_interest::invoke(&env, &contract_id, &timestamp);
+
+    // Hand-computed: periodic_coupon = (100_000 * (800 / 4)) / 100 = 200_000,
+    // days_elapsed = (3_942_000 / 86_400) * 4 = 45 * 4 = 180, days_in_period = 365,
+    // accrued = (200_000 * 180) / 365 = 98_630.
+    assert_eq!(
+        accrued,
+        BigInt::from_i64(&env, 98_630),
+        "accrued interest should match the Actual365 day-count calculation for a quarterly coupon"
+    );
+}
+
+#[test]
+fn test_accrued_interest_actual360_daily_does_not_divide_by_zero() {
+    // Actual360 paired with a Daily coupon frequency used to truncate the
+    // day-count denominator (360 / 365) to zero and panic on division.
+    let env = Env::default();
+    let contract_id = FixedBinary::from_array(&env, generate_contract_id());
+    env.register_contract(&contract_id, DebentureContract);
+
+    let issue_date = BigInt::from_i64(&env, chrono::Utc::now().timestamp());
+    let maturity = issue_date
+        .clone()
+        .add(BigInt::from_i64(&env, 3650 * 24 * 60 * 60));
+    let coupon_rate = BigInt::from_u32(&env, 800);
+    let par_value = BigInt::from_u64(&env, 1e5 as u64);
+    let debenture_holder = FixedBinary::from_array(&env, generate_contract_id());
+    let max_extension = BigInt::from_i64(&env, 30 * 24 * 60 * 60);
+
+    issue::invoke(
+        &env,
+        &contract_id,
+        &issue_date,
+        &maturity,
+        &coupon_rate,
+        &par_value,
+        &(CouponPaymentFrequency::Daily as u32),
+        &(DayCount::Actual360 as u32),
+        &max_extension,
+        &debenture_holder,
+    );
+
+    // Partway through the second daily period.
+    let timestamp = issue_date.add(BigInt::from_i64(&env, 100_000));
+    let accrued = accrued_interest::invoke(&env, &contract_id, &timestamp);
+
+    // Hand-computed: period_length = (365 * 86400) / 365 = 86400, so
+    // periods_elapsed = 100_000 / 86400 = 1, prev_coupon_date = issue + 86400.
+    // days_elapsed = ((timestamp - prev_coupon_date) / 86400) * 365 = 0 (the
+    // 13_600s remainder is under a full day), so no accrual has happened yet
+    // within this daily period — the important part is that this no longer
+    // panics on a zero denominator.
+    assert_eq!(
+        accrued,
+        BigInt::zero(&env),
+        "a sub-day offset into a daily coupon period should accrue nothing, not panic"
+    );
+}
+
+#[test]
+fn test_cashflows() {
+    let env = Env::default();
+    let contract_id = FixedBinary::from_array(&env, generate_contract_id());
+    env.register_contract(&contract_id, DebentureContract);
+
+    let issue_date = BigInt::from_i64(&env, chrono::Utc::now().timestamp());
+    // Derived from `issue_date` (rather than a second `chrono::Utc::now()`
+    // call) so it lands on exactly one annual coupon period after issue,
+    // regardless of any clock tick between the two.
+    let maturity = issue_date
+        .clone()
+        .add(BigInt::from_i64(&env, 365 * 24 * 60 * 60));
+    let coupon_rate = BigInt::from_u32(&env, 750);
+    let par_value = BigInt::from_u64(&env, 1e5 as u64);
+    let debenture_holder = FixedBinary::from_array(&env, generate_contract_id());
+
+    issue::invoke(
+        &env,
+        &contract_id,
+        &issue_date,
+        &maturity,
+        &coupon_rate,
+        &par_value,
+        &(CouponPaymentFrequency::Annually as u32),
+        &(DayCount::Actual365 as u32),
+        &BigInt::from_i64(&env, 30 * 24 * 60 * 60),
+        &debenture_holder,
+    );
+
+    let schedule = cashflows::invoke(&env, &contract_id);
+
+    // an annual debenture maturing in one year has a single cashflow: the
+    // final coupon plus the par value redemption.
+    assert_eq!(schedule.len(), 1, "expected a single cashflow");
+    let (payment_date, amount) = schedule.get(0).unwrap().unwrap();
+    assert_eq!(payment_date, maturity, "redemption should occur at maturity");
+    assert_eq!(
+        amount,
+        par_value + coupon_payment::invoke(&env, &contract_id, &maturity),
+        "redemption amount should include the final coupon"
+    );
+}
+
+#[test]
+fn test_extend_maturity() {
+    let env = Env::default();
+    let contract_id = FixedBinary::from_array(&env, generate_contract_id());
+    env.register_contract(&contract_id, DebentureContract);
+
+    let issue_date = BigInt::from_i64(&env, chrono::Utc::now().timestamp());
+    let maturity = BigInt::from_i64(
+        &env,
+        chrono::Utc::now()
+            .add(chrono::Duration::days(365))
+            .timestamp(),
+    );
+    let coupon_rate = BigInt::from_u32(&env, 750);
+    let par_value = BigInt::from_u64(&env, 1e5 as u64);
+    let debenture_holder = FixedBinary::from_array(&env, generate_contract_id());
+    let max_extension = BigInt::from_i64(&env, 30 * 24 * 60 * 60);
+
+    issue::invoke(
+        &env,
+        &contract_id,
+        &issue_date,
+        &maturity,
+        &coupon_rate,
+        &par_value,
+        &(CouponPaymentFrequency::Annually as u32),
+        &(DayCount::Actual365 as u32),
+        &max_extension,
+        &debenture_holder,
+    );
+
+    let additional_seconds = BigInt::from_i64(&env, 10 * 24 * 60 * 60);
+    extend_maturity::invoke(&env, &contract_id, &additional_seconds);
+
+    let extended_maturity = maturity::invoke(&env, &contract_id);
+    assert_eq!(
+        extended_maturity,
+        maturity + additional_seconds,
+        "maturity should be rolled forward"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Maturity extension exceeds the maximum allowed for this debenture")]
+fn test_extend_maturity_beyond_cap_panics() {
+    let env = Env::default();
+    let contract_id = FixedBinary::from_array(&env, generate_contract_id());
+    env.register_contract(&contract_id, DebentureContract);
+
+    let issue_date = BigInt::from_i64(&env, chrono::Utc::now().timestamp());
+    let maturity = BigInt::from_i64(
+        &env,
+        chrono::Utc::now()
+            .add(chrono::Duration::days(365))
+            .timestamp(),
+    );
+    let coupon_rate = BigInt::from_u32(&env, 750);
+    let par_value = BigInt::from_u64(&env, 1e5 as u64);
+    let debenture_holder = FixedBinary::from_array(&env, generate_contract_id());
+    let max_extension = BigInt::from_i64(&env, 30 * 24 * 60 * 60);
+
+    issue::invoke(
+        &env,
+        &contract_id,
+        &issue_date,
+        &maturity,
+        &coupon_rate,
+        &par_value,
+        &(CouponPaymentFrequency::Annually as u32),
+        &(DayCount::Actual365 as u32),
+        &max_extension,
+        &debenture_holder,
+    );
+
+    let too_much = BigInt::from_i64(&env, 31 * 24 * 60 * 60);
+    extend_maturity::invoke(&env, &contract_id, &too_much);
+}
+
+#[test]
+#[should_panic(expected = "additional_seconds must not be negative")]
+fn test_extend_maturity_rejects_negative_seconds() {
+    let env = Env::default();
+    let contract_id = FixedBinary::from_array(&env, generate_contract_id());
+    env.register_contract(&contract_id, DebentureContract);
+
+    let issue_date = BigInt::from_i64(&env, chrono::Utc::now().timestamp());
+    let maturity = BigInt::from_i64(
+        &env,
+        chrono::Utc::now()
+            .add(chrono::Duration::days(365))
+            .timestamp(),
+    );
+    let coupon_rate = BigInt::from_u32(&env, 750);
+    let par_value = BigInt::from_u64(&env, 1e5 as u64);
+    let debenture_holder = FixedBinary::from_array(&env, generate_contract_id());
+    let max_extension = BigInt::from_i64(&env, 30 * 24 * 60 * 60);
+
+    issue::invoke(
+        &env,
+        &contract_id,
+        &issue_date,
+        &maturity,
+        &coupon_rate,
+        &par_value,
+        &(CouponPaymentFrequency::Annually as u32),
+        &(DayCount::Actual365 as u32),
+        &max_extension,
+        &debenture_holder,
+    );
+
+    let negative = BigInt::from_i64(&env, -1 * 24 * 60 * 60);
+    extend_maturity::invoke(&env, &contract_id, &negative);
+}
+
+#[test]
+#[should_panic(expected = "this action must be authorized by the issuer")]
+fn test_extend_maturity_rejects_mismatched_invoker() {
+    let env = Env::default();
+    let contract_id = FixedBinary::from_array(&env, generate_contract_id());
+    env.register_contract(&contract_id, DebentureContract);
+    let caller_id = FixedBinary::from_array(&env, generate_contract_id());
+    env.register_contract(&caller_id, Caller);
+
+    let issue_date = BigInt::from_i64(&env, chrono::Utc::now().timestamp());
+    let maturity = BigInt::from_i64(
+        &env,
+        chrono::Utc::now()
+            .add(chrono::Duration::days(365))
+            .timestamp(),
+    );
+    let coupon_rate = BigInt::from_u32(&env, 750);
+    let par_value = BigInt::from_u64(&env, 1e5 as u64);
+    let debenture_holder = FixedBinary::from_array(&env, generate_contract_id());
+    let max_extension = BigInt::from_i64(&env, 30 * 24 * 60 * 60);
+
+    // Issued directly, so the captured issuer is the harness's own invoker
+    // identity, not `caller_id`.
+    issue::invoke(
+        &env,
+        &contract_id,
+        &issue_date,
+        &maturity,
+        &coupon_rate,
+        &par_value,
+        &(CouponPaymentFrequency::Annually as u32),
+        &(DayCount::Actual365 as u32),
+        &max_extension,
+        &debenture_holder,
+    );
+
+    let additional_seconds = BigInt::from_i64(&env, 10 * 24 * 60 * 60);
+    // Routed through `Caller`, so `Env::invoker()` resolves to `caller_id`
+    // inside `extend_maturity`, which is not the recorded issuer.
+    extend::invoke(&env, &caller_id, &contract_id, &additional_seconds);
+}
+
+#[test]
+fn test_transfer() {
+    let env = Env::default();
+    let contract_id = FixedBinary::from_array(&env, generate_contract_id());
+    env.register_contract(&contract_id, DebentureContract);
+
+    let issue_date = BigInt::from_i64(&env, chrono::Utc::now().timestamp());
+    let maturity = BigInt::from_i64(
+        &env,
+        chrono::Utc::now()
+            .add(chrono::Duration::days(365))
+            .timestamp(),
+    );
+    let coupon_rate = BigInt::from_u32(&env, 750);
+    let par_value = BigInt::from_u64(&env, 1e5 as u64);
+    // `transfer` must be invoked by the current holder, so the holder is set
+    // to the test harness's own invoker identity rather than an unrelated
+    // random value.
+    let original_holder = get_invoker(&env);
+    let new_holder = FixedBinary::from_array(&env, generate_contract_id());
+    let max_extension = BigInt::from_i64(&env, 30 * 24 * 60 * 60);
+
+    issue::invoke(
+        &env,
+        &contract_id,
+        &issue_date,
+        &maturity,
+        &coupon_rate,
+        &par_value,
+        &(CouponPaymentFrequency::Annually as u32),
+        &(DayCount::Actual365 as u32),
+        &max_extension,
+        &original_holder,
+    );
+
+    transfer::invoke(&env, &contract_id, &original_holder, &new_holder);
+}
+
+#[test]
+#[should_panic(expected = "Only the current debenture holder may transfer it")]
+fn test_transfer_rejects_stale_holder_after_transfer() {
+    let env = Env::default();
+    let contract_id = FixedBinary::from_array(&env, generate_contract_id());
+    env.register_contract(&contract_id, DebentureContract);
+
+    let issue_date = BigInt::from_i64(&env, chrono::Utc::now().timestamp());
+    let maturity = BigInt::from_i64(
+        &env,
+        chrono::Utc::now()
+            .add(chrono::Duration::days(365))
+            .timestamp(),
+    );
+    let coupon_rate = BigInt::from_u32(&env, 750);
+    let par_value = BigInt::from_u64(&env, 1e5 as u64);
+    let original_holder = get_invoker(&env);
+    let new_holder = FixedBinary::from_array(&env, generate_contract_id());
+    let max_extension = BigInt::from_i64(&env, 30 * 24 * 60 * 60);
+
+    issue::invoke(
+        &env,
+        &contract_id,
+        &issue_date,
+        &maturity,
+        &coupon_rate,
+        &par_value,
+        &(CouponPaymentFrequency::Annually as u32),
+        &(DayCount::Actual365 as u32),
+        &max_extension,
+        &original_holder,
+    );
+
+    transfer::invoke(&env, &contract_id, &original_holder, &new_holder);
+
+    // the debenture's holder should now be new_holder, not original_holder;
+    // there is no public getter for the holder, so we confirm the record was
+    // updated by observing that a second transfer attempted as the stale
+    // original_holder is rejected.
+    transfer::invoke(&env, &contract_id, &original_holder, &new_holder);
+}
+
+#[test]
+#[should_panic(expected = "transfer must be authorized by the current debenture holder")]
+fn test_transfer_rejects_mismatched_invoker() {
+    let env = Env::default();
+    let contract_id = FixedBinary::from_array(&env, generate_contract_id());
+    env.register_contract(&contract_id, DebentureContract);
+    let caller_id = FixedBinary::from_array(&env, generate_contract_id());
+    env.register_contract(&caller_id, Caller);
+
+    let issue_date = BigInt::from_i64(&env, chrono::Utc::now().timestamp());
+    let maturity = BigInt::from_i64(
+        &env,
+        chrono::Utc::now()
+            .add(chrono::Duration::days(365))
+            .timestamp(),
+    );
+    let coupon_rate = BigInt::from_u32(&env, 750);
+    let par_value = BigInt::from_u64(&env, 1e5 as u64);
+    let original_holder = get_invoker(&env);
+    let new_holder = FixedBinary::from_array(&env, generate_contract_id());
+    let max_extension = BigInt::from_i64(&env, 30 * 24 * 60 * 60);
+
+    issue::invoke(
+        &env,
+        &contract_id,
+        &issue_date,
+        &maturity,
+        &coupon_rate,
+        &par_value,
+        &(CouponPaymentFrequency::Annually as u32),
+        &(DayCount::Actual365 as u32),
+        &max_extension,
+        &original_holder,
+    );
+
+    // `from` matches the current holder, but the call is routed through
+    // `Caller`, so `Env::invoker()` resolves to `caller_id` and must be
+    // rejected even though the storage-level holder check would pass.
+    xfer::invoke(&env, &caller_id, &contract_id, &original_holder, &new_holder);
+}
+
+#[test]
+#[should_panic(expected = "Only the current debenture holder may transfer it")]
+fn test_transfer_from_non_holder_panics() {
+    let env = Env::default();
+    let contract_id = FixedBinary::from_array(&env, generate_contract_id());
+    env.register_contract(&contract_id, DebentureContract);
+
+    let issue_date = BigInt::from_i64(&env, chrono::Utc::now().timestamp());
+    let maturity = BigInt::from_i64(
+        &env,
+        chrono::Utc::now()
+            .add(chrono::Duration::days(365))
+            .timestamp(),
+    );
+    let coupon_rate = BigInt::from_u32(&env, 750);
+    let par_value = BigInt::from_u64(&env, 1e5 as u64);
+    let original_holder = FixedBinary::from_array(&env, generate_contract_id());
+    let not_the_holder = FixedBinary::from_array(&env, generate_contract_id());
+    let new_holder = FixedBinary::from_array(&env, generate_contract_id());
+    let max_extension = BigInt::from_i64(&env, 30 * 24 * 60 * 60);
+
+    issue::invoke(
+        &env,
+        &contract_id,
+        &issue_date,
+        &maturity,
+        &coupon_rate,
+        &par_value,
+        &(CouponPaymentFrequency::Annually as u32),
+        &(DayCount::Actual365 as u32),
+        &max_extension,
+        &original_holder,
+    );
+
+    transfer::invoke(&env, &contract_id, &not_the_holder, &new_holder);
+}
+
+#[test]
+fn test_accrue_compounds_over_elapsed_periods() {
+    let env = Env::default();
+    let contract_id = FixedBinary::from_array(&env, generate_contract_id());
+    env.register_contract(&contract_id, DebentureContract);
+
+    let issue_date = BigInt::from_i64(&env, chrono::Utc::now().timestamp());
+    let maturity = BigInt::from_i64(
+        &env,
+        chrono::Utc::now()
+            .add(chrono::Duration::days(3650))
+            .timestamp(),
+    );
+    let coupon_rate = BigInt::from_u32(&env, 750);
+    let par_value = BigInt::from_u64(&env, 1e5 as u64);
+    let debenture_holder = FixedBinary::from_array(&env, generate_contract_id());
+    let max_extension = BigInt::from_i64(&env, 30 * 24 * 60 * 60);
+
+    issue::invoke(
+        &env,
+        &contract_id,
+        &issue_date,
+        &maturity,
+        &coupon_rate,
+        &par_value,
+        &(CouponPaymentFrequency::Annually as u32),
+        &(DayCount::Actual365 as u32),
+        &max_extension,
+        &debenture_holder,
+    );
+
+    // before any accrual has run, no interest has compounded
+    assert_eq!(
+        compounded_interest::invoke(&env, &contract_id),
+        BigInt::zero(&env),
+        "no interest should have compounded before the first accrual"
+    );
+
+    let one_period_later = issue_date.add(BigInt::from_i64(&env, 365 * 24 * 60 * 60));
+    accrue::invoke(&env, &contract_id, &one_period_later);
+
+    assert!(
+        compounded_interest::invoke(&env, &contract_id) > BigInt::zero(&env),
+        "interest should have compounded after a full coupon period"
+    );
+}
+
+#[test]
+fn test_accrue_quarterly_divides_rate_by_frequency() {
+    let env = Env::default();
+    let contract_id = FixedBinary::from_array(&env, generate_contract_id());
+    env.register_contract(&contract_id, DebentureContract);
+
+    let issue_date = BigInt::from_i64(&env, chrono::Utc::now().timestamp());
+    let maturity = issue_date
+        .clone()
+        .add(BigInt::from_i64(&env, 3650 * 24 * 60 * 60));
+    // 4% per annum, paid quarterly.
+    let coupon_rate = BigInt::from_u32(&env, 400);
+    let par_value = BigInt::from_u64(&env, 1e5 as u64);
+    let debenture_holder = FixedBinary::from_array(&env, generate_contract_id());
+    let max_extension = BigInt::from_i64(&env, 30 * 24 * 60 * 60);
+
+    issue::invoke(
+        &env,
+        &contract_id,
+        &issue_date,
+        &maturity,
+        &coupon_rate,
+        &par_value,
+        &(CouponPaymentFrequency::Quarterly as u32),
+        &(DayCount::Actual365 as u32),
+        &max_extension,
+        &debenture_holder,
+    );
+
+    // One full year (4 quarterly periods) after issue.
+    let one_year_later = issue_date.add(BigInt::from_i64(&env, 365 * 24 * 60 * 60));
+    accrue::invoke(&env, &contract_id, &one_year_later);
+
+    // Hand-computed: rate_per_period = 1 + (0.04/4) = 1.01 (scaled by 1e9),
+    // compounded over 4 periods, applied to a par value of 100_000.
+    assert_eq!(
+        compounded_interest::invoke(&env, &contract_id),
+        BigInt::from_i64(&env, 4060),
+        "quarterly compounding should divide the annual rate by the payment frequency"
+    );
+}
+
+#[test]
+fn test_issue_with_metadata() {
+    let env = Env::default();
+    let contract_id = FixedBinary::from_array(&env, generate_contract_id());
+    env.register_contract(&contract_id, DebentureContract);
+
+    let issue_date = BigInt::from_i64(&env, chrono::Utc::now().timestamp());
+    let maturity = BigInt::from_i64(
+        &env,
+        chrono::Utc::now()
+            .add(chrono::Duration::days(365))
+            .timestamp(),
+    );
+    let coupon_rate = BigInt::from_u32(&env, 750);
+    let par_value = BigInt::from_u64(&env, 1e5 as u64);
+    let debenture_holder = FixedBinary::from_array(&env, generate_contract_id());
+    let max_extension = BigInt::from_i64(&env, 30 * 24 * 60 * 60);
+    let isin = Binary::from_array(&env, *b"US0000000001");
+    let issuer_name = Binary::from_array(&env, *b"Example Issuer Inc.");
+    let currency = Binary::from_array(&env, *b"USD");
+    let denomination = 1_000u32;
+
+    issue_with_metadata::invoke(
+        &env,
+        &contract_id,
+        &issue_date,
+        &maturity,
+        &coupon_rate,
+        &par_value,
+        &(CouponPaymentFrequency::Annually as u32),
+        &(DayCount::Actual365 as u32),
+        &max_extension,
+        &debenture_holder,
+        &isin,
+        &issuer_name,
+        &currency,
+        &denomination,
+    );
+
+    let retrieved = metadata::invoke(&env, &contract_id);
+    assert_eq!(retrieved.isin, isin, "isin should round-trip");
+    assert_eq!(
+        retrieved.denomination, denomination,
+        "denomination should round-trip"
+    );
 }